@@ -0,0 +1,500 @@
+//! Bluetooth SIG assigned Appearance values
+//!
+//! The category and subcategory tables follow Assigned Numbers §2.6. Constants are named after
+//! the SIG name. A subcategory whose name is also used by a category or by a subcategory of
+//! another category is prefixed with its category, e.g. [`Appearance::HVAC_RADIATOR`] and
+//! [`Appearance::HEATING_RADIATOR`] (Human Interface Device is shortened to `HID`).
+
+use std::fmt;
+
+/// The external appearance of a device, as assigned by the Bluetooth SIG.
+///
+/// The upper 10 bits hold the category and the lower 6 bits the subcategory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Appearance(pub u16);
+
+macro_rules! appearances {
+    ($(
+        $cat:ident = $cat_val:literal => $cat_name:literal
+        $({ $($sub:ident = $sub_val:literal => $sub_name:literal,)* })?,
+    )*) => {
+        impl Appearance {
+            $(
+                #[doc = $cat_name]
+                pub const $cat: Appearance = Appearance::new($cat_val, 0);
+                $($(
+                    #[doc = concat!($sub_name, " (", $cat_name, ")")]
+                    pub const $sub: Appearance = Appearance::new($cat_val, $sub_val);
+                )*)?
+            )*
+        }
+
+        fn category_name(category: u16) -> Option<&'static str> {
+            match category {
+                $($cat_val => Some($cat_name),)*
+                _ => None,
+            }
+        }
+
+        fn subcategory_name(category: u16, subcategory: u8) -> Option<&'static str> {
+            match (category, subcategory) {
+                $($($(($cat_val, $sub_val) => Some($sub_name),)*)?)*
+                _ => None,
+            }
+        }
+    };
+}
+
+appearances! {
+    UNKNOWN = 0x000 => "Unknown",
+    PHONE = 0x001 => "Phone",
+    COMPUTER = 0x002 => "Computer" {
+        DESKTOP_WORKSTATION = 0x01 => "Desktop Workstation",
+        SERVER_CLASS_COMPUTER = 0x02 => "Server-class Computer",
+        LAPTOP = 0x03 => "Laptop",
+        HANDHELD_PC_PDA = 0x04 => "Handheld PC/PDA (clamshell)",
+        PALM_SIZE_PC_PDA = 0x05 => "Palm-size PC/PDA",
+        WEARABLE_COMPUTER = 0x06 => "Wearable computer (watch size)",
+        TABLET = 0x07 => "Tablet",
+        DOCKING_STATION = 0x08 => "Docking Station",
+        ALL_IN_ONE = 0x09 => "All in One",
+        BLADE_SERVER = 0x0a => "Blade Server",
+        CONVERTIBLE = 0x0b => "Convertible",
+        DETACHABLE = 0x0c => "Detachable",
+        IOT_GATEWAY = 0x0d => "IoT Gateway",
+        MINI_PC = 0x0e => "Mini PC",
+        STICK_PC = 0x0f => "Stick PC",
+    },
+    WATCH = 0x003 => "Watch" {
+        SPORTS_WATCH = 0x01 => "Sports Watch",
+        SMARTWATCH = 0x02 => "Smartwatch",
+    },
+    CLOCK = 0x004 => "Clock",
+    DISPLAY = 0x005 => "Display",
+    REMOTE_CONTROL = 0x006 => "Remote Control",
+    EYE_GLASSES = 0x007 => "Eye-glasses",
+    TAG = 0x008 => "Tag",
+    KEYRING = 0x009 => "Keyring",
+    MEDIA_PLAYER = 0x00a => "Media Player",
+    BARCODE_SCANNER = 0x00b => "Barcode Scanner",
+    THERMOMETER = 0x00c => "Thermometer" {
+        EAR_THERMOMETER = 0x01 => "Ear Thermometer",
+    },
+    HEART_RATE_SENSOR = 0x00d => "Heart Rate Sensor" {
+        HEART_RATE_BELT = 0x01 => "Heart Rate Belt",
+    },
+    BLOOD_PRESSURE = 0x00e => "Blood Pressure" {
+        ARM_BLOOD_PRESSURE = 0x01 => "Arm Blood Pressure",
+        WRIST_BLOOD_PRESSURE = 0x02 => "Wrist Blood Pressure",
+    },
+    HUMAN_INTERFACE_DEVICE = 0x00f => "Human Interface Device" {
+        KEYBOARD = 0x01 => "Keyboard",
+        MOUSE = 0x02 => "Mouse",
+        JOYSTICK = 0x03 => "Joystick",
+        GAMEPAD = 0x04 => "Gamepad",
+        DIGITIZER_TABLET = 0x05 => "Digitizer Tablet",
+        CARD_READER = 0x06 => "Card Reader",
+        DIGITAL_PEN = 0x07 => "Digital Pen",
+        HID_BARCODE_SCANNER = 0x08 => "Barcode Scanner",
+        TOUCHPAD = 0x09 => "Touchpad",
+        PRESENTATION_REMOTE = 0x0a => "Presentation Remote",
+    },
+    GLUCOSE_METER = 0x010 => "Glucose Meter",
+    RUNNING_WALKING_SENSOR = 0x011 => "Running Walking Sensor" {
+        IN_SHOE_RUNNING_WALKING_SENSOR = 0x01 => "In-Shoe Running Walking Sensor",
+        ON_SHOE_RUNNING_WALKING_SENSOR = 0x02 => "On-Shoe Running Walking Sensor",
+        ON_HIP_RUNNING_WALKING_SENSOR = 0x03 => "On-Hip Running Walking Sensor",
+    },
+    CYCLING = 0x012 => "Cycling" {
+        CYCLING_COMPUTER = 0x01 => "Cycling Computer",
+        SPEED_SENSOR = 0x02 => "Speed Sensor",
+        CADENCE_SENSOR = 0x03 => "Cadence Sensor",
+        POWER_SENSOR = 0x04 => "Power Sensor",
+        SPEED_AND_CADENCE_SENSOR = 0x05 => "Speed and Cadence Sensor",
+    },
+    CONTROL_DEVICE = 0x013 => "Control Device" {
+        SWITCH = 0x01 => "Switch",
+        MULTI_SWITCH = 0x02 => "Multi-switch",
+        BUTTON = 0x03 => "Button",
+        SLIDER = 0x04 => "Slider",
+        ROTARY_SWITCH = 0x05 => "Rotary Switch",
+        TOUCH_PANEL = 0x06 => "Touch Panel",
+        SINGLE_SWITCH = 0x07 => "Single Switch",
+        DOUBLE_SWITCH = 0x08 => "Double Switch",
+        TRIPLE_SWITCH = 0x09 => "Triple Switch",
+        BATTERY_SWITCH = 0x0a => "Battery Switch",
+        ENERGY_HARVESTING_SWITCH = 0x0b => "Energy Harvesting Switch",
+        PUSH_BUTTON = 0x0c => "Push Button",
+        DIAL = 0x0d => "Dial",
+    },
+    NETWORK_DEVICE = 0x014 => "Network Device" {
+        ACCESS_POINT = 0x01 => "Access Point",
+        MESH_DEVICE = 0x02 => "Mesh Device",
+        MESH_NETWORK_PROXY = 0x03 => "Mesh Network Proxy",
+    },
+    SENSOR = 0x015 => "Sensor" {
+        MOTION_SENSOR = 0x01 => "Motion Sensor",
+        AIR_QUALITY_SENSOR = 0x02 => "Air quality Sensor",
+        TEMPERATURE_SENSOR = 0x03 => "Temperature Sensor",
+        HUMIDITY_SENSOR = 0x04 => "Humidity Sensor",
+        LEAK_SENSOR = 0x05 => "Leak Sensor",
+        SMOKE_SENSOR = 0x06 => "Smoke Sensor",
+        OCCUPANCY_SENSOR = 0x07 => "Occupancy Sensor",
+        CONTACT_SENSOR = 0x08 => "Contact Sensor",
+        CARBON_MONOXIDE_SENSOR = 0x09 => "Carbon Monoxide Sensor",
+        CARBON_DIOXIDE_SENSOR = 0x0a => "Carbon Dioxide Sensor",
+        AMBIENT_LIGHT_SENSOR = 0x0b => "Ambient Light Sensor",
+        ENERGY_SENSOR = 0x0c => "Energy Sensor",
+        COLOR_LIGHT_SENSOR = 0x0d => "Color Light Sensor",
+        RAIN_SENSOR = 0x0e => "Rain Sensor",
+        FIRE_SENSOR = 0x0f => "Fire Sensor",
+        WIND_SENSOR = 0x10 => "Wind Sensor",
+        PROXIMITY_SENSOR = 0x11 => "Proximity Sensor",
+        MULTI_SENSOR = 0x12 => "Multi-Sensor",
+        FLUSH_MOUNTED_SENSOR = 0x13 => "Flush Mounted Sensor",
+        CEILING_MOUNTED_SENSOR = 0x14 => "Ceiling Mounted Sensor",
+        WALL_MOUNTED_SENSOR = 0x15 => "Wall Mounted Sensor",
+        MULTISENSOR = 0x16 => "Multisensor",
+        ENERGY_METER = 0x17 => "Energy Meter",
+        FLAME_DETECTOR = 0x18 => "Flame Detector",
+        VEHICLE_TIRE_PRESSURE_SENSOR = 0x19 => "Vehicle Tire Pressure Sensor",
+    },
+    LIGHT_FIXTURES = 0x016 => "Light Fixtures" {
+        WALL_LIGHT = 0x01 => "Wall Light",
+        CEILING_LIGHT = 0x02 => "Ceiling Light",
+        FLOOR_LIGHT = 0x03 => "Floor Light",
+        CABINET_LIGHT = 0x04 => "Cabinet Light",
+        DESK_LIGHT = 0x05 => "Desk Light",
+        TROFFER_LIGHT = 0x06 => "Troffer Light",
+        PENDANT_LIGHT = 0x07 => "Pendant Light",
+        IN_GROUND_LIGHT = 0x08 => "In-ground Light",
+        FLOOD_LIGHT = 0x09 => "Flood Light",
+        UNDERWATER_LIGHT = 0x0a => "Underwater Light",
+        BOLLARD_WITH_LIGHT = 0x0b => "Bollard with Light",
+        PATHWAY_LIGHT = 0x0c => "Pathway Light",
+        GARDEN_LIGHT = 0x0d => "Garden Light",
+        POLE_TOP_LIGHT = 0x0e => "Pole-top Light",
+        SPOTLIGHT = 0x0f => "Spotlight",
+        LINEAR_LIGHT = 0x10 => "Linear Light",
+        STREET_LIGHT = 0x11 => "Street Light",
+        SHELVES_LIGHT = 0x12 => "Shelves Light",
+        BAY_LIGHT = 0x13 => "Bay Light",
+        EMERGENCY_EXIT_LIGHT = 0x14 => "Emergency Exit Light",
+        LIGHT_CONTROLLER = 0x15 => "Light Controller",
+        LIGHT_DRIVER = 0x16 => "Light Driver",
+        BULB = 0x17 => "Bulb",
+        LOW_BAY_LIGHT = 0x18 => "Low-bay Light",
+        HIGH_BAY_LIGHT = 0x19 => "High-bay Light",
+    },
+    FAN = 0x017 => "Fan" {
+        CEILING_FAN = 0x01 => "Ceiling Fan",
+        AXIAL_FAN = 0x02 => "Axial Fan",
+        EXHAUST_FAN = 0x03 => "Exhaust Fan",
+        PEDESTAL_FAN = 0x04 => "Pedestal Fan",
+        DESK_FAN = 0x05 => "Desk Fan",
+        WALL_FAN = 0x06 => "Wall Fan",
+    },
+    HVAC = 0x018 => "HVAC" {
+        THERMOSTAT = 0x01 => "Thermostat",
+        HVAC_HUMIDIFIER = 0x02 => "Humidifier",
+        DEHUMIDIFIER = 0x03 => "De-humidifier",
+        HEATER = 0x04 => "Heater",
+        HVAC_RADIATOR = 0x05 => "Radiator",
+        HVAC_BOILER = 0x06 => "Boiler",
+        HVAC_HEAT_PUMP = 0x07 => "Heat Pump",
+        HVAC_INFRARED_HEATER = 0x08 => "Infrared Heater",
+        HVAC_RADIANT_PANEL_HEATER = 0x09 => "Radiant Panel Heater",
+        HVAC_FAN_HEATER = 0x0a => "Fan Heater",
+        HVAC_AIR_CURTAIN = 0x0b => "Air Curtain",
+    },
+    AIR_CONDITIONING = 0x019 => "Air Conditioning",
+    HUMIDIFIER = 0x01a => "Humidifier",
+    HEATING = 0x01b => "Heating" {
+        HEATING_RADIATOR = 0x01 => "Radiator",
+        HEATING_BOILER = 0x02 => "Boiler",
+        HEATING_HEAT_PUMP = 0x03 => "Heat Pump",
+        HEATING_INFRARED_HEATER = 0x04 => "Infrared Heater",
+        HEATING_RADIANT_PANEL_HEATER = 0x05 => "Radiant Panel Heater",
+        HEATING_FAN_HEATER = 0x06 => "Fan Heater",
+        HEATING_AIR_CURTAIN = 0x07 => "Air Curtain",
+    },
+    ACCESS_CONTROL = 0x01c => "Access Control" {
+        ACCESS_DOOR = 0x01 => "Access Door",
+        GARAGE_DOOR = 0x02 => "Garage Door",
+        EMERGENCY_EXIT_DOOR = 0x03 => "Emergency Exit Door",
+        ACCESS_LOCK = 0x04 => "Access Lock",
+        ELEVATOR = 0x05 => "Elevator",
+        WINDOW = 0x06 => "Window",
+        ENTRANCE_GATE = 0x07 => "Entrance Gate",
+        DOOR_LOCK = 0x08 => "Door Lock",
+        LOCKER = 0x09 => "Locker",
+    },
+    MOTORIZED_DEVICE = 0x01d => "Motorized Device" {
+        MOTORIZED_GATE = 0x01 => "Motorized Gate",
+        AWNING = 0x02 => "Awning",
+        BLINDS_OR_SHADES = 0x03 => "Blinds or Shades",
+        CURTAINS = 0x04 => "Curtains",
+        SCREEN = 0x05 => "Screen",
+    },
+    POWER_DEVICE = 0x01e => "Power Device" {
+        POWER_OUTLET = 0x01 => "Power Outlet",
+        POWER_STRIP = 0x02 => "Power Strip",
+        PLUG = 0x03 => "Plug",
+        POWER_SUPPLY = 0x04 => "Power Supply",
+        LED_DRIVER = 0x05 => "LED Driver",
+        FLUORESCENT_LAMP_GEAR = 0x06 => "Fluorescent Lamp Gear",
+        HID_LAMP_GEAR = 0x07 => "HID Lamp Gear",
+        CHARGE_CASE = 0x08 => "Charge Case",
+        POWER_BANK = 0x09 => "Power Bank",
+    },
+    LIGHT_SOURCE = 0x01f => "Light Source" {
+        INCANDESCENT_LIGHT_BULB = 0x01 => "Incandescent Light Bulb",
+        LED_LAMP = 0x02 => "LED Lamp",
+        HID_LAMP = 0x03 => "HID Lamp",
+        FLUORESCENT_LAMP = 0x04 => "Fluorescent Lamp",
+        LED_ARRAY = 0x05 => "LED Array",
+        MULTI_COLOR_LED_ARRAY = 0x06 => "Multi-Color LED Array",
+        LOW_VOLTAGE_HALOGEN = 0x07 => "Low voltage halogen",
+        OLED = 0x08 => "Organic light emitting diode (OLED)",
+    },
+    WINDOW_COVERING = 0x020 => "Window Covering" {
+        WINDOW_SHADES = 0x01 => "Window Shades",
+        WINDOW_BLINDS = 0x02 => "Window Blinds",
+        WINDOW_AWNING = 0x03 => "Window Awning",
+        WINDOW_CURTAIN = 0x04 => "Window Curtain",
+        EXTERIOR_SHUTTER = 0x05 => "Exterior Shutter",
+        EXTERIOR_SCREEN = 0x06 => "Exterior Screen",
+    },
+    AUDIO_SINK = 0x021 => "Audio Sink" {
+        STANDALONE_SPEAKER = 0x01 => "Standalone Speaker",
+        SOUNDBAR = 0x02 => "Soundbar",
+        BOOKSHELF_SPEAKER = 0x03 => "Bookshelf Speaker",
+        STANDMOUNTED_SPEAKER = 0x04 => "Standmounted Speaker",
+        SPEAKERPHONE = 0x05 => "Speakerphone",
+    },
+    AUDIO_SOURCE = 0x022 => "Audio Source" {
+        MICROPHONE = 0x01 => "Microphone",
+        ALARM = 0x02 => "Alarm",
+        BELL = 0x03 => "Bell",
+        HORN = 0x04 => "Horn",
+        BROADCASTING_DEVICE = 0x05 => "Broadcasting Device",
+        SERVICE_DESK = 0x06 => "Service Desk",
+        KIOSK = 0x07 => "Kiosk",
+        BROADCASTING_ROOM = 0x08 => "Broadcasting Room",
+        AUDITORIUM = 0x09 => "Auditorium",
+    },
+    MOTORIZED_VEHICLE = 0x023 => "Motorized Vehicle" {
+        CAR = 0x01 => "Car",
+        LARGE_GOODS_VEHICLE = 0x02 => "Large Goods Vehicle",
+        TWO_WHEELED_VEHICLE = 0x03 => "2-Wheeled Vehicle",
+        MOTORBIKE = 0x04 => "Motorbike",
+        SCOOTER = 0x05 => "Scooter",
+        MOPED = 0x06 => "Moped",
+        THREE_WHEELED_VEHICLE = 0x07 => "3-Wheeled Vehicle",
+        LIGHT_VEHICLE = 0x08 => "Light Vehicle",
+        QUAD_BIKE = 0x09 => "Quad Bike",
+        MINIBUS = 0x0a => "Minibus",
+        BUS = 0x0b => "Bus",
+        TROLLEY = 0x0c => "Trolley",
+        AGRICULTURAL_VEHICLE = 0x0d => "Agricultural Vehicle",
+        CAMPER_CARAVAN = 0x0e => "Camper / Caravan",
+        RECREATIONAL_VEHICLE = 0x0f => "Recreational Vehicle / Motor Home",
+    },
+    DOMESTIC_APPLIANCE = 0x024 => "Domestic Appliance" {
+        REFRIGERATOR = 0x01 => "Refrigerator",
+        FREEZER = 0x02 => "Freezer",
+        OVEN = 0x03 => "Oven",
+        MICROWAVE = 0x04 => "Microwave",
+        TOASTER = 0x05 => "Toaster",
+        WASHING_MACHINE = 0x06 => "Washing Machine",
+        DRYER = 0x07 => "Dryer",
+        COFFEE_MAKER = 0x08 => "Coffee maker",
+        CLOTHES_IRON = 0x09 => "Clothes iron",
+        CURLING_IRON = 0x0a => "Curling iron",
+        HAIR_DRYER = 0x0b => "Hair dryer",
+        VACUUM_CLEANER = 0x0c => "Vacuum cleaner",
+        ROBOTIC_VACUUM_CLEANER = 0x0d => "Robotic vacuum cleaner",
+        RICE_COOKER = 0x0e => "Rice cooker",
+        CLOTHES_STEAMER = 0x0f => "Clothes steamer",
+    },
+    WEARABLE_AUDIO_DEVICE = 0x025 => "Wearable Audio Device" {
+        EARBUD = 0x01 => "Earbud",
+        HEADSET = 0x02 => "Headset",
+        HEADPHONES = 0x03 => "Headphones",
+        NECK_BAND = 0x04 => "Neck Band",
+    },
+    AIRCRAFT = 0x026 => "Aircraft" {
+        LIGHT_AIRCRAFT = 0x01 => "Light Aircraft",
+        MICROLIGHT = 0x02 => "Microlight",
+        PARAGLIDER = 0x03 => "Paraglider",
+        LARGE_PASSENGER_AIRCRAFT = 0x04 => "Large Passenger Aircraft",
+    },
+    AV_EQUIPMENT = 0x027 => "AV Equipment" {
+        AMPLIFIER = 0x01 => "Amplifier",
+        RECEIVER = 0x02 => "Receiver",
+        RADIO = 0x03 => "Radio",
+        TUNER = 0x04 => "Tuner",
+        TURNTABLE = 0x05 => "Turntable",
+        CD_PLAYER = 0x06 => "CD Player",
+        DVD_PLAYER = 0x07 => "DVD Player",
+        BLURAY_PLAYER = 0x08 => "Bluray Player",
+        OPTICAL_DISC_PLAYER = 0x09 => "Optical Disc Player",
+        SET_TOP_BOX = 0x0a => "Set-Top Box",
+    },
+    DISPLAY_EQUIPMENT = 0x028 => "Display Equipment" {
+        TELEVISION = 0x01 => "Television",
+        MONITOR = 0x02 => "Monitor",
+        PROJECTOR = 0x03 => "Projector",
+    },
+    HEARING_AID = 0x029 => "Hearing aid" {
+        IN_EAR_HEARING_AID = 0x01 => "In-ear hearing aid",
+        BEHIND_EAR_HEARING_AID = 0x02 => "Behind-ear hearing aid",
+        COCHLEAR_IMPLANT = 0x03 => "Cochlear Implant",
+    },
+    GAMING = 0x02a => "Gaming" {
+        HOME_VIDEO_GAME_CONSOLE = 0x01 => "Home Video Game Console",
+        PORTABLE_HANDHELD_CONSOLE = 0x02 => "Portable handheld console",
+    },
+    SIGNAGE = 0x02b => "Signage" {
+        DIGITAL_SIGNAGE = 0x01 => "Digital Signage",
+        ELECTRONIC_LABEL = 0x02 => "Electronic Label",
+    },
+    PULSE_OXIMETER = 0x031 => "Pulse Oximeter" {
+        FINGERTIP_PULSE_OXIMETER = 0x01 => "Fingertip Pulse Oximeter",
+        WRIST_WORN_PULSE_OXIMETER = 0x02 => "Wrist Worn Pulse Oximeter",
+    },
+    WEIGHT_SCALE = 0x032 => "Weight Scale",
+    PERSONAL_MOBILITY_DEVICE = 0x033 => "Personal Mobility Device" {
+        POWERED_WHEELCHAIR = 0x01 => "Powered Wheelchair",
+        MOBILITY_SCOOTER = 0x02 => "Mobility Scooter",
+    },
+    CONTINUOUS_GLUCOSE_MONITOR = 0x034 => "Continuous Glucose Monitor",
+    INSULIN_PUMP = 0x035 => "Insulin Pump" {
+        DURABLE_INSULIN_PUMP = 0x01 => "Insulin Pump, durable pump",
+        PATCH_INSULIN_PUMP = 0x04 => "Insulin Pump, patch pump",
+        INSULIN_PEN = 0x08 => "Insulin Pen",
+    },
+    MEDICATION_DELIVERY = 0x036 => "Medication Delivery",
+    SPIROMETER = 0x037 => "Spirometer" {
+        HANDHELD_SPIROMETER = 0x01 => "Handheld Spirometer",
+    },
+    OUTDOOR_SPORTS_ACTIVITY = 0x051 => "Outdoor Sports Activity" {
+        LOCATION_DISPLAY = 0x01 => "Location Display",
+        LOCATION_AND_NAVIGATION_DISPLAY = 0x02 => "Location and Navigation Display",
+        LOCATION_POD = 0x03 => "Location Pod",
+        LOCATION_AND_NAVIGATION_POD = 0x04 => "Location and Navigation Pod",
+    },
+}
+
+impl Appearance {
+    /// The advertising data type carrying an [`Appearance`] value.
+    pub const AD_TYPE: u8 = 0x19;
+
+    /// Creates an [`Appearance`] from its category and subcategory.
+    ///
+    /// The category is truncated to 10 bits and the subcategory to 6 bits.
+    pub const fn new(category: u16, subcategory: u8) -> Self {
+        Appearance(((category & 0x3ff) << 6) | (subcategory as u16 & 0x3f))
+    }
+
+    /// Parses the payload of an Appearance (0x19) advertising data structure.
+    ///
+    /// Returns `None` if `data` is not exactly two bytes long.
+    pub fn from_ad_data(data: &[u8]) -> Option<Self> {
+        match *data {
+            [lo, hi] => Some(Appearance(u16::from_le_bytes([lo, hi]))),
+            _ => None,
+        }
+    }
+
+    /// The 10-bit category of this appearance.
+    pub const fn category(self) -> u16 {
+        self.0 >> 6
+    }
+
+    /// The 6-bit subcategory of this appearance. Zero is the generic subcategory.
+    pub const fn subcategory(self) -> u8 {
+        (self.0 & 0x3f) as u8
+    }
+
+    /// The SIG-assigned name of this appearance's category, if known.
+    pub fn category_name(self) -> Option<&'static str> {
+        category_name(self.category())
+    }
+
+    /// The SIG-assigned name of this appearance, if known.
+    ///
+    /// Falls back to the category name for unknown subcategories.
+    pub fn name(self) -> Option<&'static str> {
+        subcategory_name(self.category(), self.subcategory()).or_else(|| self.category_name())
+    }
+}
+
+impl From<u16> for Appearance {
+    fn from(value: u16) -> Self {
+        Appearance(value)
+    }
+}
+
+impl From<Appearance> for u16 {
+    fn from(value: Appearance) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Appearance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "Appearance({:#06x})", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Appearance;
+
+    #[test]
+    fn category_and_subcategory() {
+        let appearance = Appearance(0x03c1);
+        assert_eq!(appearance, Appearance::KEYBOARD);
+        assert_eq!(appearance.category(), 0x00f);
+        assert_eq!(appearance.subcategory(), 0x01);
+        assert_eq!(Appearance::HUMAN_INTERFACE_DEVICE.0, 0x03c0);
+    }
+
+    #[test]
+    fn from_ad_data() {
+        assert_eq!(
+            Appearance::from_ad_data(&[0x41, 0x03]),
+            Some(Appearance::HEART_RATE_BELT)
+        );
+        assert_eq!(Appearance::from_ad_data(&[0x41]), None);
+        assert_eq!(Appearance::from_ad_data(&[0x41, 0x03, 0x00]), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Appearance::MOUSE.to_string(), "Mouse");
+        assert_eq!(
+            Appearance::new(0x00f, 0x0a).to_string(),
+            "Presentation Remote"
+        );
+        assert_eq!(Appearance::new(0x025, 0x01).to_string(), "Earbud");
+        assert_eq!(Appearance::LAPTOP.to_string(), "Laptop");
+        assert_eq!(Appearance::HVAC_RADIATOR.category_name(), Some("HVAC"));
+        assert_eq!(
+            Appearance::HEATING_RADIATOR.category_name(),
+            Some("Heating")
+        );
+        assert_eq!(Appearance::HEATING_RADIATOR.to_string(), "Radiator");
+        assert_eq!(Appearance::new(0x035, 0x08).to_string(), "Insulin Pen");
+        assert_eq!(Appearance::new(0x035, 0x02).to_string(), "Insulin Pump");
+        assert_eq!(
+            Appearance::new(0x00f, 0x3f).to_string(),
+            "Human Interface Device"
+        );
+        assert_eq!(Appearance::new(0x3ff, 0).to_string(), "Appearance(0xffc0)");
+    }
+}
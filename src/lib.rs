@@ -1,3 +1,8 @@
+pub mod appearance;
+pub mod profiles;
+
+pub use appearance::Appearance;
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -0,0 +1,10 @@
+//! Decoders for advertisement and GATT payloads
+//!
+//! Covers formats defined by the Bluetooth SIG (Transport Discovery) and vendor formats (Google
+//! Fast Pair, Google/Apple Exposure Notification, Apple Find My). None of them need extra
+//! dependencies, so they are always available.
+
+pub mod exposure_notification;
+pub mod fast_pair;
+pub mod find_my;
+pub mod tds;
@@ -0,0 +1,72 @@
+//! Exposure Notification service data
+//!
+//! Decodes the service data broadcast by the Google/Apple Exposure Notification protocol.
+
+use std::fmt;
+
+/// The 16-bit UUID of the Exposure Notification service.
+pub const SERVICE_UUID16: u16 = 0xfd6f;
+
+/// Errors returned when decoding Exposure Notification data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The service data was not exactly 20 bytes long
+    InvalidLength,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength => f.write_str("Exposure Notification data must be 20 bytes long"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The payload of an Exposure Notification advertisement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExposureNotification {
+    /// The Rolling Proximity Identifier
+    pub rolling_proximity_identifier: [u8; 16],
+    /// The Associated Encrypted Metadata
+    pub associated_encrypted_metadata: [u8; 4],
+}
+
+impl ExposureNotification {
+    /// Parses the service data associated with [`SERVICE_UUID16`].
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        if data.len() != 20 {
+            return Err(Error::InvalidLength);
+        }
+        let mut rolling_proximity_identifier = [0; 16];
+        let mut associated_encrypted_metadata = [0; 4];
+        rolling_proximity_identifier.copy_from_slice(&data[..16]);
+        associated_encrypted_metadata.copy_from_slice(&data[16..]);
+        Ok(ExposureNotification {
+            rolling_proximity_identifier,
+            associated_encrypted_metadata,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let data: Vec<u8> = (0..20).collect();
+        let en = ExposureNotification::parse(&data).unwrap();
+        assert_eq!(en.rolling_proximity_identifier[..], data[..16]);
+        assert_eq!(en.associated_encrypted_metadata, [16, 17, 18, 19]);
+        assert_eq!(
+            ExposureNotification::parse(&data[..19]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            ExposureNotification::parse(&[0; 21]),
+            Err(Error::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Google Fast Pair service data
+//!
+//! Decodes the service data a Fast Pair provider broadcasts, both while discoverable (the model
+//! ID) and while not discoverable (the account key data with optional salt and battery levels).
+
+use std::fmt;
+
+/// The 16-bit UUID of the Fast Pair service.
+pub const SERVICE_UUID16: u16 = 0xfe2c;
+
+const TYPE_ACCOUNT_KEY_FILTER_SHOW_UI: u8 = 0b0000;
+const TYPE_SALT: u8 = 0b0001;
+const TYPE_ACCOUNT_KEY_FILTER_HIDE_UI: u8 = 0b0010;
+const TYPE_BATTERY_SHOW_UI: u8 = 0b0011;
+const TYPE_BATTERY_HIDE_UI: u8 = 0b0100;
+
+/// Errors returned when decoding Fast Pair data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The data ended in the middle of a field
+    Truncated,
+    /// The account key data did not contain an account key filter field
+    MissingAccountKeyFilter,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Truncated => f.write_str("Fast Pair data is truncated"),
+            Error::MissingAccountKeyFilter => {
+                f.write_str("Fast Pair account key data has no account key filter")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The payload of a Fast Pair advertisement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FastPairAdvertisement {
+    /// The provider is discoverable and advertises its 24-bit model ID
+    Discoverable {
+        /// The model ID registered with Google
+        model_id: u32,
+    },
+    /// The provider is not discoverable and advertises its account key data
+    NotDiscoverable(AccountKeyData),
+}
+
+/// Account key data advertised by a provider that is not discoverable
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountKeyData {
+    /// The version from the first byte of the service data
+    pub version: u8,
+    /// The flags from the first byte of the service data
+    pub flags: u8,
+    /// The account key filter, empty if the provider has no account keys
+    pub account_key_filter: Vec<u8>,
+    /// Whether seekers should show a pairing notification for this provider
+    pub show_ui: bool,
+    /// The salt applied to the account key filter
+    pub salt: Option<Vec<u8>>,
+    /// The battery levels of the provider's components
+    pub battery: Option<BatteryNotification>,
+}
+
+/// Battery levels advertised alongside the account key data
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BatteryNotification {
+    /// Whether seekers should show a battery notification
+    pub show_ui: bool,
+    /// The battery level of each component (e.g. left bud, right bud, case)
+    pub levels: Vec<BatteryLevel>,
+}
+
+/// The battery level of a single component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BatteryLevel {
+    /// Whether the component is charging
+    pub charging: bool,
+    /// The charge in percent, or `None` if unknown
+    pub percent: Option<u8>,
+}
+
+impl From<u8> for BatteryLevel {
+    fn from(value: u8) -> Self {
+        let level = value & 0x7f;
+        BatteryLevel {
+            charging: value & 0x80 != 0,
+            percent: (level != 0x7f).then_some(level),
+        }
+    }
+}
+
+impl FastPairAdvertisement {
+    /// Parses the service data associated with [`SERVICE_UUID16`].
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        match *data {
+            [a, b, c] => Ok(FastPairAdvertisement::Discoverable {
+                model_id: u32::from_be_bytes([0, a, b, c]),
+            }),
+            [header, ref fields @ ..] => {
+                AccountKeyData::parse(header, fields).map(FastPairAdvertisement::NotDiscoverable)
+            }
+            [] => Err(Error::Truncated),
+        }
+    }
+}
+
+impl AccountKeyData {
+    fn parse(header: u8, mut fields: &[u8]) -> Result<Self, Error> {
+        let mut filter = None;
+        let mut salt = None;
+        let mut battery = None;
+
+        while let [len_type, rest @ ..] = fields {
+            let len = usize::from(len_type >> 4);
+            if rest.len() < len {
+                return Err(Error::Truncated);
+            }
+            let (value, rest) = rest.split_at(len);
+            match len_type & 0x0f {
+                TYPE_ACCOUNT_KEY_FILTER_SHOW_UI => filter = Some((value.to_vec(), true)),
+                TYPE_ACCOUNT_KEY_FILTER_HIDE_UI => filter = Some((value.to_vec(), false)),
+                TYPE_SALT => salt = Some(value.to_vec()),
+                ty @ (TYPE_BATTERY_SHOW_UI | TYPE_BATTERY_HIDE_UI) => {
+                    battery = Some(BatteryNotification {
+                        show_ui: ty == TYPE_BATTERY_SHOW_UI,
+                        levels: value.iter().copied().map(BatteryLevel::from).collect(),
+                    })
+                }
+                // Unknown field types are skipped for forward compatibility
+                _ => (),
+            }
+            fields = rest;
+        }
+
+        let (account_key_filter, show_ui) = filter.ok_or(Error::MissingAccountKeyFilter)?;
+        Ok(AccountKeyData {
+            version: header >> 4,
+            flags: header & 0x0f,
+            account_key_filter,
+            show_ui,
+            salt,
+            battery,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_discoverable() {
+        assert_eq!(
+            FastPairAdvertisement::parse(&[0x2c, 0x36, 0x3d]),
+            Ok(FastPairAdvertisement::Discoverable { model_id: 0x2c363d })
+        );
+    }
+
+    #[test]
+    fn parse_not_discoverable() {
+        let data = [
+            0x00, 0x42, 0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x5a, 0x33, 0xe4, 0x7f, 0x32,
+        ];
+        assert_eq!(
+            FastPairAdvertisement::parse(&data),
+            Ok(FastPairAdvertisement::NotDiscoverable(AccountKeyData {
+                version: 0,
+                flags: 0,
+                account_key_filter: vec![0xaa, 0xbb, 0xcc, 0xdd],
+                show_ui: false,
+                salt: Some(vec![0x5a]),
+                battery: Some(BatteryNotification {
+                    show_ui: true,
+                    levels: vec![
+                        BatteryLevel {
+                            charging: true,
+                            percent: Some(100),
+                        },
+                        BatteryLevel {
+                            charging: false,
+                            percent: None,
+                        },
+                        BatteryLevel {
+                            charging: false,
+                            percent: Some(50),
+                        },
+                    ],
+                }),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_empty_account_keys() {
+        let FastPairAdvertisement::NotDiscoverable(data) =
+            FastPairAdvertisement::parse(&[0x00, 0x00]).unwrap()
+        else {
+            panic!("expected account key data");
+        };
+        assert!(data.account_key_filter.is_empty());
+        assert!(data.show_ui);
+        assert_eq!(data.salt, None);
+
+        let FastPairAdvertisement::NotDiscoverable(data) =
+            FastPairAdvertisement::parse(&[0x00, 0x11, 0x5a, 0x00]).unwrap()
+        else {
+            panic!("expected account key data");
+        };
+        assert!(data.account_key_filter.is_empty());
+        assert_eq!(data.salt, Some(vec![0x5a]));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(FastPairAdvertisement::parse(&[]), Err(Error::Truncated));
+        assert_eq!(
+            FastPairAdvertisement::parse(&[0x00, 0x40, 0xaa, 0xbb]),
+            Err(Error::Truncated)
+        );
+        assert_eq!(
+            FastPairAdvertisement::parse(&[0x00, 0x11, 0x5a, 0x33]),
+            Err(Error::Truncated)
+        );
+        assert_eq!(
+            FastPairAdvertisement::parse(&[0x00, 0x11, 0x5a, 0x03]),
+            Err(Error::MissingAccountKeyFilter)
+        );
+    }
+}
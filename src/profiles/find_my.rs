@@ -0,0 +1,190 @@
+//! Apple Find My network advertisements
+//!
+//! Decodes the offline finding payload that AirTags and other Find My accessories broadcast in
+//! Apple manufacturer specific data while separated from their owner. The payload carries the
+//! part of the accessory's rotating public key that does not fit in its random address.
+
+use std::fmt;
+
+/// The Bluetooth SIG company identifier assigned to Apple.
+pub const APPLE_COMPANY_ID: u16 = 0x004c;
+
+/// The Apple manufacturer data type of the offline finding payload.
+pub const TYPE_OFFLINE_FINDING: u8 = 0x12;
+
+const OFFLINE_FINDING_LEN: usize = 25;
+
+/// Errors returned when decoding Find My data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The data ended in the middle of a field
+    Truncated,
+    /// The manufacturer data does not contain an offline finding payload
+    NotFound,
+    /// The offline finding payload has a length other than the 25-byte separated form
+    UnsupportedLength(u8),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Truncated => f.write_str("Find My data is truncated"),
+            Error::NotFound => {
+                f.write_str("Apple manufacturer data has no offline finding payload")
+            }
+            Error::UnsupportedLength(len) => {
+                write!(
+                    f,
+                    "unsupported Find My offline finding payload length {len}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The battery level reported in the status byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BatteryLevel {
+    /// The battery is full
+    Full,
+    /// The battery is at a medium level
+    Medium,
+    /// The battery is low
+    Low,
+    /// The battery is critically low
+    CriticallyLow,
+}
+
+/// The offline finding payload of a Find My accessory separated from its owner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FindMyAdvertisement {
+    /// The status byte
+    pub status: u8,
+    /// Bytes 6 to 27 of the 28-byte public key
+    pub public_key_fragment: [u8; 22],
+    /// The top two bits of the first public key byte, which the random address cannot carry
+    pub public_key_bits: u8,
+    /// The hint byte
+    pub hint: u8,
+}
+
+impl FindMyAdvertisement {
+    /// Parses Apple manufacturer specific data, excluding the [`APPLE_COMPANY_ID`] prefix.
+    ///
+    /// The data is a sequence of type-length-value entries; the [`TYPE_OFFLINE_FINDING`] entry is
+    /// decoded and the others are skipped.
+    pub fn parse(mut data: &[u8]) -> Result<Self, Error> {
+        while let [ty, len, rest @ ..] = data {
+            let len_usize = usize::from(*len);
+            if rest.len() < len_usize {
+                return Err(Error::Truncated);
+            }
+            let (value, rest) = rest.split_at(len_usize);
+            if *ty == TYPE_OFFLINE_FINDING {
+                if len_usize != OFFLINE_FINDING_LEN {
+                    return Err(Error::UnsupportedLength(*len));
+                }
+                let mut public_key_fragment = [0; 22];
+                public_key_fragment.copy_from_slice(&value[1..23]);
+                return Ok(FindMyAdvertisement {
+                    status: value[0],
+                    public_key_fragment,
+                    public_key_bits: value[23] & 0b11,
+                    hint: value[24],
+                });
+            }
+            data = rest;
+        }
+
+        if data.is_empty() {
+            Err(Error::NotFound)
+        } else {
+            Err(Error::Truncated)
+        }
+    }
+
+    /// The battery level from bits 6-7 of the status byte.
+    pub fn battery_level(&self) -> BatteryLevel {
+        match self.status >> 6 {
+            0b00 => BatteryLevel::Full,
+            0b01 => BatteryLevel::Medium,
+            0b10 => BatteryLevel::Low,
+            _ => BatteryLevel::CriticallyLow,
+        }
+    }
+
+    /// Reconstructs the 28-byte public key from the advertiser's random address.
+    ///
+    /// `address` is in the usual most-significant-byte-first order. Its first six bytes carry
+    /// the start of the key, except that the top two bits of the first byte are forced on by the
+    /// static random address format and are replaced by [`FindMyAdvertisement::public_key_bits`].
+    pub fn public_key(&self, address: [u8; 6]) -> [u8; 28] {
+        let mut key = [0; 28];
+        key[..6].copy_from_slice(&address);
+        key[0] = (address[0] & 0x3f) | (self.public_key_bits << 6);
+        key[6..].copy_from_slice(&self.public_key_fragment);
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offline_finding() -> Vec<u8> {
+        let mut data = vec![TYPE_OFFLINE_FINDING, 0x19, 0x90];
+        data.extend(0x10..0x26);
+        data.extend([0x02, 0x7e]);
+        data
+    }
+
+    #[test]
+    fn parse() {
+        let adv = FindMyAdvertisement::parse(&offline_finding()).unwrap();
+        assert_eq!(adv.status, 0x90);
+        assert_eq!(adv.battery_level(), BatteryLevel::Low);
+        assert_eq!(adv.public_key_fragment[0], 0x10);
+        assert_eq!(adv.public_key_fragment[21], 0x25);
+        assert_eq!(adv.public_key_bits, 0x02);
+        assert_eq!(adv.hint, 0x7e);
+
+        let key = adv.public_key([0xc1, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        assert_eq!(key[..6], [0x81, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        assert_eq!(key[6..], adv.public_key_fragment);
+    }
+
+    #[test]
+    fn parse_skips_other_types() {
+        let mut data = vec![0x10, 0x02, 0xaa, 0xbb];
+        data.extend(offline_finding());
+        assert_eq!(
+            FindMyAdvertisement::parse(&data),
+            FindMyAdvertisement::parse(&offline_finding())
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(FindMyAdvertisement::parse(&[]), Err(Error::NotFound));
+        assert_eq!(
+            FindMyAdvertisement::parse(&[0x10, 0x02, 0xaa, 0xbb]),
+            Err(Error::NotFound)
+        );
+        assert_eq!(
+            FindMyAdvertisement::parse(&[0x10, 0x02, 0xaa]),
+            Err(Error::Truncated)
+        );
+        assert_eq!(FindMyAdvertisement::parse(&[0x10]), Err(Error::Truncated));
+        assert_eq!(
+            FindMyAdvertisement::parse(&[TYPE_OFFLINE_FINDING, 0x02, 0x00, 0x00]),
+            Err(Error::UnsupportedLength(0x02))
+        );
+        let data = offline_finding();
+        assert_eq!(
+            FindMyAdvertisement::parse(&data[..20]),
+            Err(Error::Truncated)
+        );
+    }
+}
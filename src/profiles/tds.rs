@@ -0,0 +1,357 @@
+//! Transport Discovery Service (TDS)
+//!
+//! Decodes the Transport Discovery Data advertising data type and encodes/decodes the TDS
+//! Control Point procedures used to activate an alternative transport (e.g. Wi-Fi) on a
+//! peripheral.
+
+use std::fmt;
+
+/// The 16-bit UUID of the Transport Discovery service.
+pub const SERVICE_UUID16: u16 = 0x1824;
+
+/// The 16-bit UUID of the TDS Control Point characteristic.
+pub const CONTROL_POINT_UUID16: u16 = 0x2abc;
+
+/// The organization ID assigned to the Bluetooth SIG.
+pub const ORG_BLUETOOTH_SIG: u8 = 0x01;
+
+/// Errors returned when decoding or encoding TDS data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The data ended in the middle of a structure
+    Truncated,
+    /// Transport data longer than 255 bytes cannot be encoded in a transport block
+    DataTooLong,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Truncated => f.write_str("TDS data is truncated"),
+            Error::DataTooLong => f.write_str("TDS transport data is longer than 255 bytes"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The role a device plays for a transport
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// The role is not specified
+    NotSpecified,
+    /// The device is seeking a provider of the transport
+    Seeker,
+    /// The device provides the transport
+    Provider,
+    /// The device is both a seeker and a provider
+    SeekerAndProvider,
+}
+
+/// The state of a transport on the advertising device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransportState {
+    /// The transport is off
+    Off,
+    /// The transport is on
+    On,
+    /// The transport is temporarily unavailable
+    TemporarilyUnavailable,
+    /// A value reserved for future use
+    Reserved,
+}
+
+/// A single transport block from Transport Discovery Data
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransportBlock {
+    /// The organization that defines the format of [`TransportBlock::data`]
+    ///
+    /// The reserved value `0x00` is kept as-is, like the other fields reserved for future use.
+    pub organization_id: u8,
+    /// The role of the advertising device for this transport
+    pub role: Role,
+    /// Whether [`TransportBlock::data`] is incomplete and must be read from the device
+    pub data_incomplete: bool,
+    /// The state of the transport
+    pub state: TransportState,
+    /// The flag bits 5-7 reserved for future use, kept in place so blocks re-encode unchanged
+    pub reserved_flags: u8,
+    /// Organization-specific transport data
+    pub data: Vec<u8>,
+}
+
+impl TransportBlock {
+    fn flags(&self) -> u8 {
+        let role = match self.role {
+            Role::NotSpecified => 0b00,
+            Role::Seeker => 0b01,
+            Role::Provider => 0b10,
+            Role::SeekerAndProvider => 0b11,
+        };
+        let state = match self.state {
+            TransportState::Off => 0b00,
+            TransportState::On => 0b01,
+            TransportState::TemporarilyUnavailable => 0b10,
+            TransportState::Reserved => 0b11,
+        };
+        role | (u8::from(self.data_incomplete) << 2)
+            | (state << 3)
+            | (self.reserved_flags & 0b1110_0000)
+    }
+
+    /// Encodes this block in the Transport Discovery Data format.
+    ///
+    /// Returns [`Error::DataTooLong`] if `data` is longer than 255 bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let len = u8::try_from(self.data.len()).map_err(|_| Error::DataTooLong)?;
+        let mut bytes = Vec::with_capacity(3 + self.data.len());
+        bytes.extend_from_slice(&[self.organization_id, self.flags(), len]);
+        bytes.extend_from_slice(&self.data);
+        Ok(bytes)
+    }
+}
+
+/// The payload of a Transport Discovery Data advertising data structure
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TransportDiscoveryData {
+    /// The transport blocks, in advertised order
+    pub blocks: Vec<TransportBlock>,
+}
+
+impl TransportDiscoveryData {
+    /// The advertising data type carrying Transport Discovery Data.
+    pub const AD_TYPE: u8 = 0x26;
+
+    /// Parses the payload of a Transport Discovery Data (0x26) advertising data structure.
+    pub fn from_ad_data(mut data: &[u8]) -> Result<Self, Error> {
+        let mut blocks = Vec::new();
+        while !data.is_empty() {
+            let [organization_id, flags, len, rest @ ..] = data else {
+                return Err(Error::Truncated);
+            };
+            let len = usize::from(*len);
+            if rest.len() < len {
+                return Err(Error::Truncated);
+            }
+
+            let role = match flags & 0b11 {
+                0b00 => Role::NotSpecified,
+                0b01 => Role::Seeker,
+                0b10 => Role::Provider,
+                _ => Role::SeekerAndProvider,
+            };
+            let state = match (flags >> 3) & 0b11 {
+                0b00 => TransportState::Off,
+                0b01 => TransportState::On,
+                0b10 => TransportState::TemporarilyUnavailable,
+                _ => TransportState::Reserved,
+            };
+
+            blocks.push(TransportBlock {
+                organization_id: *organization_id,
+                role,
+                data_incomplete: flags & 0b100 != 0,
+                state,
+                reserved_flags: flags & 0b1110_0000,
+                data: rest[..len].to_vec(),
+            });
+            data = &rest[len..];
+        }
+        Ok(TransportDiscoveryData { blocks })
+    }
+
+    /// Encodes the transport blocks in the Transport Discovery Data format.
+    ///
+    /// Returns [`Error::DataTooLong`] if any block's `data` is longer than 255 bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        for block in &self.blocks {
+            bytes.extend(block.to_bytes()?);
+        }
+        Ok(bytes)
+    }
+}
+
+/// The op code of the TDS Control Point Activate Transport procedure.
+pub const OP_ACTIVATE_TRANSPORT: u8 = 0x01;
+
+/// A request written to the TDS Control Point
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ControlPointRequest {
+    /// Requests that the peripheral activate the transport defined by `organization_id`
+    ActivateTransport {
+        /// The organization that defines the transport
+        organization_id: u8,
+        /// Organization-specific parameters for the transport
+        data: Vec<u8>,
+    },
+}
+
+impl ControlPointRequest {
+    /// Encodes this request as the value to write to the TDS Control Point.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            ControlPointRequest::ActivateTransport {
+                organization_id,
+                data,
+            } => {
+                let mut bytes = Vec::with_capacity(2 + data.len());
+                bytes.extend_from_slice(&[OP_ACTIVATE_TRANSPORT, *organization_id]);
+                bytes.extend_from_slice(data);
+                bytes
+            }
+        }
+    }
+}
+
+/// The result of a TDS Control Point procedure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResultCode {
+    /// The procedure succeeded
+    Success,
+    /// The op code is not supported by the peripheral
+    OpCodeNotSupported,
+    /// A parameter was invalid
+    InvalidParameter,
+    /// The organization ID is not supported by the peripheral
+    UnsupportedOrganizationId,
+    /// The procedure failed
+    OperationFailed,
+    /// A value reserved for future use
+    Reserved(u8),
+}
+
+impl From<u8> for ResultCode {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => ResultCode::Success,
+            0x01 => ResultCode::OpCodeNotSupported,
+            0x02 => ResultCode::InvalidParameter,
+            0x03 => ResultCode::UnsupportedOrganizationId,
+            0x04 => ResultCode::OperationFailed,
+            _ => ResultCode::Reserved(value),
+        }
+    }
+}
+
+/// A response indicated by the TDS Control Point
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ControlPointResponse {
+    /// The op code of the request this response answers
+    pub request_op_code: u8,
+    /// The result of the procedure
+    pub result: ResultCode,
+    /// Organization-specific response parameters
+    pub parameter: Vec<u8>,
+}
+
+impl ControlPointResponse {
+    /// Parses a value indicated by the TDS Control Point.
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        match data {
+            [request_op_code, result, parameter @ ..] => Ok(ControlPointResponse {
+                request_op_code: *request_op_code,
+                result: ResultCode::from(*result),
+                parameter: parameter.to_vec(),
+            }),
+            _ => Err(Error::Truncated),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_blocks() {
+        let data = [0x02, 0b0_1010, 0x02, 0xaa, 0xbb, 0x01, 0b1_0011, 0x00];
+        let tdd = TransportDiscoveryData::from_ad_data(&data).unwrap();
+        assert_eq!(
+            tdd.blocks,
+            vec![
+                TransportBlock {
+                    organization_id: 0x02,
+                    role: Role::Provider,
+                    data_incomplete: false,
+                    state: TransportState::On,
+                    reserved_flags: 0,
+                    data: vec![0xaa, 0xbb],
+                },
+                TransportBlock {
+                    organization_id: ORG_BLUETOOTH_SIG,
+                    role: Role::SeekerAndProvider,
+                    data_incomplete: false,
+                    state: TransportState::TemporarilyUnavailable,
+                    reserved_flags: 0,
+                    data: vec![],
+                },
+            ]
+        );
+        assert_eq!(tdd.to_bytes().unwrap(), data);
+    }
+
+    #[test]
+    fn reserved_flags_round_trip() {
+        let data = [ORG_BLUETOOTH_SIG, 0b1010_1101, 0x01, 0xcc];
+        let blocks = TransportDiscoveryData::from_ad_data(&data).unwrap().blocks;
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].role, Role::Seeker);
+        assert!(blocks[0].data_incomplete);
+        assert_eq!(blocks[0].state, TransportState::On);
+        assert_eq!(blocks[0].reserved_flags, 0b1010_0000);
+        assert_eq!(blocks[0].to_bytes().unwrap(), data);
+    }
+
+    #[test]
+    fn encode_data_too_long() {
+        let block = TransportBlock {
+            organization_id: ORG_BLUETOOTH_SIG,
+            role: Role::NotSpecified,
+            data_incomplete: false,
+            state: TransportState::Off,
+            reserved_flags: 0,
+            data: vec![0; 256],
+        };
+        assert_eq!(block.to_bytes(), Err(Error::DataTooLong));
+    }
+
+    #[test]
+    fn parse_invalid_blocks() {
+        assert_eq!(
+            TransportDiscoveryData::from_ad_data(&[]),
+            Ok(TransportDiscoveryData::default())
+        );
+        assert_eq!(
+            TransportDiscoveryData::from_ad_data(&[0x01, 0x00]),
+            Err(Error::Truncated)
+        );
+        assert_eq!(
+            TransportDiscoveryData::from_ad_data(&[0x01, 0x00, 0x02, 0xaa]),
+            Err(Error::Truncated)
+        );
+    }
+
+    #[test]
+    fn reserved_organization_id_round_trip() {
+        let data = [0x00, 0b0_1001, 0x01, 0xcc];
+        let blocks = TransportDiscoveryData::from_ad_data(&data).unwrap().blocks;
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].organization_id, 0x00);
+        assert_eq!(blocks[0].to_bytes().unwrap(), data);
+    }
+
+    #[test]
+    fn control_point() {
+        let request = ControlPointRequest::ActivateTransport {
+            organization_id: 0x02,
+            data: vec![0x10],
+        };
+        assert_eq!(request.to_bytes(), [OP_ACTIVATE_TRANSPORT, 0x02, 0x10]);
+
+        let response = ControlPointResponse::parse(&[OP_ACTIVATE_TRANSPORT, 0x03]).unwrap();
+        assert_eq!(response.result, ResultCode::UnsupportedOrganizationId);
+        assert!(response.parameter.is_empty());
+        assert_eq!(ControlPointResponse::parse(&[0x01]), Err(Error::Truncated));
+    }
+}